@@ -1,10 +1,24 @@
 //! Block part of API implementation.
 
 // Built-in uses
-use std::str::FromStr;
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 // External uses
-use actix_web::{web, Scope};
+use actix::{Actor, ActorContext, ActorFutureExt, AsyncContext, StreamHandler, WrapFuture};
+use actix_web::{web, Either, HttpRequest, HttpResponse, Scope};
+use actix_web_actors::ws;
+use chrono::{DateTime, Utc};
+use futures::future;
+use once_cell::sync::OnceCell;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry, TextEncoder};
+use serde::Deserialize;
+use tokio::sync::{mpsc, watch};
+use tokio_stream::wrappers::{ReceiverStream, WatchStream};
 
 // Workspace uses
 use zksync_api_client::rest::v02::{block::BlockInfo, transaction::Transaction};
@@ -23,27 +37,247 @@ use super::{
 };
 use crate::utils::block_details_cache::BlockDetailsCache;
 
+/// Prometheus metrics collected for the `block` API scope.
+#[derive(Clone)]
+struct BlockApiMetrics {
+    requests_total: IntCounterVec,
+    request_latency_seconds: HistogramVec,
+    request_errors_total: IntCounterVec,
+    cache_hits_total: IntCounter,
+    cache_misses_total: IntCounter,
+    registry: Registry,
+}
+
+impl BlockApiMetrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let requests_total = IntCounterVec::new(
+            Opts::new(
+                "block_api_requests_total",
+                "Total number of requests served by each `block` API endpoint",
+            ),
+            &["endpoint"],
+        )
+        .expect("metric options are valid");
+        let request_latency_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "block_api_request_latency_seconds",
+                "Latency of `block` API endpoint handlers",
+            ),
+            &["endpoint"],
+        )
+        .expect("metric options are valid");
+        let request_errors_total = IntCounterVec::new(
+            Opts::new(
+                "block_api_request_errors_total",
+                "Total number of requests that each `block` API endpoint failed to serve",
+            ),
+            &["endpoint"],
+        )
+        .expect("metric options are valid");
+        let cache_hits_total = IntCounter::new(
+            "block_details_cache_hits_total",
+            "Number of block lookups served from the cache",
+        )
+        .expect("metric options are valid");
+        let cache_misses_total = IntCounter::new(
+            "block_details_cache_misses_total",
+            "Number of block lookups that missed the cache and hit storage",
+        )
+        .expect("metric options are valid");
+
+        registry
+            .register(Box::new(requests_total.clone()))
+            .expect("metric is not registered twice");
+        registry
+            .register(Box::new(request_latency_seconds.clone()))
+            .expect("metric is not registered twice");
+        registry
+            .register(Box::new(request_errors_total.clone()))
+            .expect("metric is not registered twice");
+        registry
+            .register(Box::new(cache_hits_total.clone()))
+            .expect("metric is not registered twice");
+        registry
+            .register(Box::new(cache_misses_total.clone()))
+            .expect("metric is not registered twice");
+
+        Self {
+            requests_total,
+            request_latency_seconds,
+            request_errors_total,
+            cache_hits_total,
+            cache_misses_total,
+            registry,
+        }
+    }
+
+    /// Records a single handled request for `endpoint`, along with how long it took.
+    fn observe_request(&self, endpoint: &str, started_at: Instant) {
+        self.requests_total.with_label_values(&[endpoint]).inc();
+        self.request_latency_seconds
+            .with_label_values(&[endpoint])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+
+    fn observe_request_error(&self, endpoint: &str) {
+        self.request_errors_total.with_label_values(&[endpoint]).inc();
+    }
+
+    fn observe_cache_lookup(&self, was_cached: bool) {
+        if was_cached {
+            self.cache_hits_total.inc();
+        } else {
+            self.cache_misses_total.inc();
+        }
+    }
+
+    /// Serializes all collected metrics using the Prometheus text exposition format.
+    fn gather(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("metrics are always encodable");
+        buffer
+    }
+}
+
+impl fmt::Debug for BlockApiMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockApiMetrics").finish()
+    }
+}
+
+/// Committed-vs-finalized filter for `block_page`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum BlockStatusFilter {
+    Committed,
+    Finalized,
+}
+
+/// Optional search/filter parameters accepted alongside `PaginationQuery<BlockNumber>`
+/// on `block_page`, e.g. `block?from=100&limit=20&status=finalized&after=2023-01-01T00:00:00Z`.
+#[derive(Debug, Deserialize, Default)]
+struct BlockFilter {
+    status: Option<BlockStatusFilter>,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+}
+
+/// `PaginationQuery<BlockNumber>` composed with `BlockFilter`, passed to `Paginate`
+/// the same way `BlockAndTxHash` composes a cursor on top of `TxHash`.
+struct BlockPageQuery {
+    pagination: PaginationQuery<BlockNumber>,
+    filter: BlockFilter,
+}
+
+/// Last block numbers observed by the `block/subscribe` poller.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct BlockUpdate {
+    last_committed: BlockNumber,
+    last_finalized: BlockNumber,
+}
+
 /// Shared data between `api/v0.2/block` endpoints.
 #[derive(Debug, Clone)]
 struct ApiBlockData {
     pool: ConnectionPool,
     /// Verified blocks cache.
     cache: BlockDetailsCache,
+    metrics: BlockApiMetrics,
+    /// Latest committed/finalized block numbers, fanned out to `block/subscribe`.
+    block_updates: watch::Receiver<BlockUpdate>,
 }
 
+/// Shared across every `ApiBlockData` instance, so all actix workers report the
+/// same Prometheus registry and poll storage through a single background task.
+static METRICS: OnceCell<BlockApiMetrics> = OnceCell::new();
+static BLOCK_UPDATES: OnceCell<watch::Receiver<BlockUpdate>> = OnceCell::new();
+
 impl ApiBlockData {
     fn new(pool: ConnectionPool, cache: BlockDetailsCache) -> Self {
-        Self { pool, cache }
+        let metrics = METRICS.get_or_init(BlockApiMetrics::new).clone();
+        let block_updates = BLOCK_UPDATES
+            .get_or_init(|| Self::spawn_block_update_poller(pool.clone()))
+            .clone();
+
+        Self {
+            pool,
+            cache,
+            metrics,
+            block_updates,
+        }
+    }
+
+    /// Polls storage for the latest committed/finalized block numbers and publishes
+    /// any advance on the returned watch channel, which every `block/subscribe`
+    /// connection subscribes to. Runs on its own thread and runtime so it outlives
+    /// whichever caller's runtime happened to invoke `ApiBlockData::new` first.
+    fn spawn_block_update_poller(pool: ConnectionPool) -> watch::Receiver<BlockUpdate> {
+        let (sender, receiver) = watch::channel(BlockUpdate::default());
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build block update poller runtime");
+
+            runtime.block_on(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(1));
+                loop {
+                    interval.tick().await;
+
+                    let mut storage = match pool.access_storage().await {
+                        Ok(storage) => storage,
+                        Err(_) => continue,
+                    };
+                    let last_committed =
+                        storage.chain().block_schema().get_last_committed_block().await;
+                    let last_finalized = storage
+                        .chain()
+                        .block_schema()
+                        .get_last_verified_confirmed_block()
+                        .await;
+                    drop(storage);
+
+                    if let (Ok(last_committed), Ok(last_finalized)) = (last_committed, last_finalized) {
+                        if sender
+                            .send(BlockUpdate {
+                                last_committed,
+                                last_finalized,
+                            })
+                            .is_err()
+                        {
+                            // No `block/subscribe` connections are listening anymore.
+                            return;
+                        }
+                    }
+                }
+            });
+        });
+
+        receiver
     }
 
     /// Returns information about block with the specified number.
     ///
     /// This method caches some of the verified blocks.
     async fn block_info(&self, block_number: BlockNumber) -> Result<Option<BlockDetails>, Error> {
-        self.cache
-            .get(&self.pool, block_number)
-            .await
-            .map_err(Error::storage)
+        let started_at = Instant::now();
+        let lookup = self.cache.get(&self.pool, block_number).await;
+        self.metrics.observe_request("block_info", started_at);
+
+        let (details, was_cached) = match lookup {
+            Ok(lookup) => lookup,
+            Err(err) => {
+                self.metrics.observe_request_error("block_info");
+                return Err(Error::storage(err));
+            }
+        };
+        self.metrics.observe_cache_lookup(was_cached);
+        Ok(details)
     }
 
     async fn get_block_number_by_position(
@@ -67,12 +301,59 @@ impl ApiBlockData {
         }
     }
 
+    /// Resolves a batch of block positions (numbers or `last_committed`/`last_finalized`),
+    /// deduplicating both positions and resolved block numbers before fetching.
+    async fn batch_block_info(&self, positions: &[String]) -> Result<Vec<Option<BlockInfo>>, Error> {
+        let mut distinct_positions: Vec<&String> = positions.iter().collect();
+        distinct_positions.sort_unstable();
+        distinct_positions.dedup();
+
+        let mut resolved = HashMap::with_capacity(distinct_positions.len());
+        for position in distinct_positions {
+            let number = self.get_block_number_by_position(position).await?;
+            resolved.insert(position.clone(), number);
+        }
+
+        let mut distinct_numbers: Vec<BlockNumber> = resolved.values().copied().collect();
+        distinct_numbers.sort_unstable();
+        distinct_numbers.dedup();
+
+        let lookups = distinct_numbers.iter().map(|&number| self.block_info(number));
+        let results = future::join_all(lookups).await;
+
+        let mut by_number = HashMap::with_capacity(distinct_numbers.len());
+        for (number, result) in distinct_numbers.into_iter().zip(results) {
+            by_number.insert(number, result?.map(BlockInfo::from));
+        }
+
+        Ok(positions
+            .iter()
+            .map(|position| by_number[&resolved[position]].clone())
+            .collect())
+    }
+
     async fn block_page(
         &self,
         query: PaginationQuery<BlockNumber>,
+        filter: BlockFilter,
     ) -> Result<Paginated<BlockInfo, BlockNumber>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
-        storage.paginate(&query).await
+        let started_at = Instant::now();
+        let result = match self.pool.access_storage().await {
+            Ok(mut storage) => {
+                storage
+                    .paginate(&BlockPageQuery {
+                        pagination: query,
+                        filter,
+                    })
+                    .await
+            }
+            Err(err) => Err(Error::storage(err)),
+        };
+        self.metrics.observe_request("block_page", started_at);
+        if result.is_err() {
+            self.metrics.observe_request_error("block_page");
+        }
+        result
     }
 
     async fn transaction_page(
@@ -80,7 +361,7 @@ impl ApiBlockData {
         block_number: BlockNumber,
         query: PaginationQuery<TxHash>,
     ) -> Result<Paginated<Transaction, BlockAndTxHash>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let started_at = Instant::now();
 
         let new_query = PaginationQuery {
             from: BlockAndTxHash {
@@ -91,7 +372,93 @@ impl ApiBlockData {
             direction: query.direction,
         };
 
-        storage.paginate(&new_query).await
+        let result = match self.pool.access_storage().await {
+            Ok(mut storage) => storage.paginate(&new_query).await,
+            Err(err) => Err(Error::storage(err)),
+        };
+        self.metrics
+            .observe_request("transaction_page", started_at);
+        if result.is_err() {
+            self.metrics.observe_request_error("transaction_page");
+        }
+        result
+    }
+
+    /// Streams transactions of `block_number` as newline-delimited JSON, fetching them
+    /// from storage in fixed-size batches.
+    fn transaction_stream(
+        &self,
+        block_number: BlockNumber,
+        query: PaginationQuery<TxHash>,
+    ) -> HttpResponse {
+        const TRANSACTION_STREAM_BATCH_SIZE: u32 = 100;
+
+        let (sender, receiver) = mpsc::channel(256);
+        let pool = self.pool.clone();
+
+        tokio::spawn(async move {
+            let mut cursor = query.from;
+            // Pagination is inclusive of `from`, so later batches must drop it.
+            let mut skip_cursor_row = false;
+
+            loop {
+                let mut storage = match pool.access_storage().await {
+                    Ok(storage) => storage,
+                    Err(err) => {
+                        let _ = sender.send(Err(Error::storage(err))).await;
+                        return;
+                    }
+                };
+
+                let batch_query = PaginationQuery {
+                    from: BlockAndTxHash {
+                        block_number,
+                        tx_hash: cursor,
+                    },
+                    limit: TRANSACTION_STREAM_BATCH_SIZE,
+                    direction: query.direction,
+                };
+                let batch = match storage.paginate(&batch_query).await {
+                    Ok(batch) => batch,
+                    Err(err) => {
+                        let _ = sender.send(Err(err)).await;
+                        return;
+                    }
+                };
+                drop(storage);
+
+                let batch_len = batch.list.len();
+                let rows = if skip_cursor_row
+                    && batch.list.first().map(|tx| &tx.tx_hash) == Some(&cursor)
+                {
+                    &batch.list[1..]
+                } else {
+                    &batch.list[..]
+                };
+
+                for transaction in rows {
+                    let mut line =
+                        serde_json::to_vec(transaction).expect("transaction is always serializable");
+                    line.push(b'\n');
+                    if sender.send(Ok(web::Bytes::from(line))).await.is_err() {
+                        // The client disconnected, stop producing further batches.
+                        return;
+                    }
+                }
+
+                match batch.list.last() {
+                    Some(last) if batch_len == TRANSACTION_STREAM_BATCH_SIZE as usize => {
+                        cursor = last.tx_hash;
+                        skip_cursor_row = true;
+                    }
+                    _ => return,
+                }
+            }
+        });
+
+        HttpResponse::Ok()
+            .content_type("application/x-ndjson")
+            .streaming(ReceiverStream::new(receiver))
     }
 
     async fn get_last_committed_block_number(&self) -> QueryResult<BlockNumber> {
@@ -118,8 +485,9 @@ impl ApiBlockData {
 async fn block_pagination(
     data: web::Data<ApiBlockData>,
     web::Query(query): web::Query<PaginationQuery<BlockNumber>>,
+    web::Query(filter): web::Query<BlockFilter>,
 ) -> ApiResult<Paginated<BlockInfo, BlockNumber>> {
-    data.block_page(query).await.into()
+    data.block_page(query, filter).await.into()
 }
 
 // TODO: take `block_position` as enum.
@@ -145,11 +513,19 @@ async fn block_by_number(
         .into()
 }
 
+/// Toggles NDJSON streaming mode on `block_transactions`, e.g. `?stream=true`.
+#[derive(Debug, Deserialize)]
+struct StreamQuery {
+    #[serde(default)]
+    stream: bool,
+}
+
 async fn block_transactions(
     data: web::Data<ApiBlockData>,
     web::Path(block_position): web::Path<String>,
     web::Query(query): web::Query<PaginationQuery<TxHash>>,
-) -> ApiResult<Paginated<Transaction, BlockAndTxHash>> {
+    web::Query(StreamQuery { stream }): web::Query<StreamQuery>,
+) -> Either<ApiResult<Paginated<Transaction, BlockAndTxHash>>, HttpResponse> {
     let block_number: BlockNumber;
 
     match data.get_block_number_by_position(&block_position).await {
@@ -157,11 +533,172 @@ async fn block_transactions(
             block_number = number;
         }
         Err(err) => {
-            return err.into();
+            return Either::A(Err(err).into());
+        }
+    }
+
+    if stream {
+        Either::B(data.transaction_stream(block_number, query))
+    } else {
+        Either::A(data.transaction_page(block_number, query).await.into())
+    }
+}
+
+/// Body of `POST block/batch`: a list of block positions using the same grammar as
+/// the `{block_number}` path segment (a number or `last_committed`/`last_finalized`).
+#[derive(Debug, Deserialize)]
+struct BlockBatchBody {
+    positions: Vec<String>,
+}
+
+/// Upper bound on `positions` in a single `POST block/batch` request.
+const MAX_BLOCK_BATCH_SIZE: usize = 100;
+
+async fn block_batch(
+    data: web::Data<ApiBlockData>,
+    web::Json(body): web::Json<BlockBatchBody>,
+) -> Either<ApiResult<Vec<Option<BlockInfo>>>, HttpResponse> {
+    if body.positions.len() > MAX_BLOCK_BATCH_SIZE {
+        return Either::B(HttpResponse::BadRequest().body(format!(
+            "at most {} block positions may be requested per batch",
+            MAX_BLOCK_BATCH_SIZE
+        )));
+    }
+
+    Either::A(data.batch_block_info(&body.positions).await.into())
+}
+
+/// Exposes the `block` API metrics in the Prometheus text exposition format.
+async fn block_metrics(data: web::Data<ApiBlockData>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(data.metrics.gather())
+}
+
+/// Which side of a `block/subscribe` connection a client wants to hear about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockSubscriptionKind {
+    Committed,
+    Finalized,
+    Both,
+}
+
+impl BlockSubscriptionKind {
+    fn wants_committed(self) -> bool {
+        self != Self::Finalized
+    }
+
+    fn wants_finalized(self) -> bool {
+        self != Self::Committed
+    }
+}
+
+/// WebSocket actor backing `block/subscribe`. Remembers the block numbers of the last
+/// update it handled, so each update back-fills every block since the previous one,
+/// including blocks skipped between ticks. A block whose lookup fails is not sent.
+struct BlockSubscriber {
+    data: web::Data<ApiBlockData>,
+    kind: BlockSubscriptionKind,
+    updates: watch::Receiver<BlockUpdate>,
+    last_sent: BlockUpdate,
+}
+
+impl Actor for BlockSubscriber {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.add_stream(WatchStream::new(self.updates.clone()));
+    }
+}
+
+impl StreamHandler<BlockUpdate> for BlockSubscriber {
+    fn handle(&mut self, update: BlockUpdate, ctx: &mut Self::Context) {
+        let from = self.last_sent;
+        self.last_sent = update;
+        if from.last_committed == update.last_committed && from.last_finalized == update.last_finalized
+        {
+            return;
+        }
+
+        let data = self.data.clone();
+        let kind = self.kind;
+        let fut = async move {
+            let mut frames = Vec::new();
+
+            if kind.wants_committed() {
+                for number in (*from.last_committed + 1)..=*update.last_committed {
+                    if let Ok(Some(details)) = data.block_info(BlockNumber(number)).await {
+                        frames.push(serde_json::json!({
+                            "status": "committed",
+                            "block": BlockInfo::from(details),
+                        }));
+                    }
+                }
+            }
+            if kind.wants_finalized() {
+                for number in (*from.last_finalized + 1)..=*update.last_finalized {
+                    if let Ok(Some(details)) = data.block_info(BlockNumber(number)).await {
+                        frames.push(serde_json::json!({
+                            "status": "finalized",
+                            "block": BlockInfo::from(details),
+                        }));
+                    }
+                }
+            }
+
+            frames
+        };
+
+        ctx.spawn(fut.into_actor(self).map(|frames, _subscriber, ctx| {
+            for frame in frames {
+                ctx.text(frame.to_string());
+            }
+        }));
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for BlockSubscriber {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        match msg {
+            Ok(ws::Message::Ping(msg)) => ctx.pong(&msg),
+            Ok(ws::Message::Close(reason)) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            Err(_) => ctx.stop(),
+            _ => {}
         }
     }
+}
+
+/// Picks which blocks a new `block/subscribe` connection hears about, e.g.
+/// `block/subscribe?kind=finalized`. Defaults to both.
+#[derive(Debug, Deserialize)]
+struct SubscriptionQuery {
+    kind: Option<String>,
+}
 
-    data.transaction_page(block_number, query).await.into()
+async fn block_subscribe(
+    req: HttpRequest,
+    stream: web::Payload,
+    data: web::Data<ApiBlockData>,
+    web::Query(query): web::Query<SubscriptionQuery>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let kind = match query.kind.as_deref() {
+        Some("committed") => BlockSubscriptionKind::Committed,
+        Some("finalized") => BlockSubscriptionKind::Finalized,
+        _ => BlockSubscriptionKind::Both,
+    };
+    let updates = data.block_updates.clone();
+    let last_sent = *updates.borrow();
+
+    let subscriber = BlockSubscriber {
+        data,
+        kind,
+        updates,
+        last_sent,
+    };
+    ws::start(subscriber, &req, stream)
 }
 
 pub fn api_scope(pool: ConnectionPool, cache: BlockDetailsCache) -> Scope {
@@ -170,6 +707,9 @@ pub fn api_scope(pool: ConnectionPool, cache: BlockDetailsCache) -> Scope {
     web::scope("block")
         .data(data)
         .route("", web::get().to(block_pagination))
+        .route("batch", web::post().to(block_batch))
+        .route("metrics", web::get().to(block_metrics))
+        .route("subscribe", web::get().to(block_subscribe))
         .route("{block_number}", web::get().to(block_by_number))
         .route(
             "{block_number}/transaction",
@@ -186,6 +726,8 @@ mod tests {
         },
         *,
     };
+    use futures::StreamExt;
+    use std::collections::HashSet;
     use zksync_api_client::rest::v02::ApiVersion;
     use zksync_types::pagination::PaginationDirection;
 
@@ -278,4 +820,170 @@ mod tests {
         server.stop().await;
         Ok(())
     }
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn v02_test_block_metrics_are_recorded() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+
+        let data = ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10));
+        let before = String::from_utf8(data.metrics.gather())?;
+
+        data.block_info(BlockNumber(1)).await?;
+
+        let after = String::from_utf8(data.metrics.gather())?;
+        assert_ne!(before, after, "block_info should move the request/cache counters");
+        assert!(after.contains("block_api_requests_total"));
+        assert!(after.contains("block_details_cache_hits_total"));
+        assert!(after.contains("block_details_cache_misses_total"));
+
+        Ok(())
+    }
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn v02_test_block_transaction_stream_has_no_duplicates() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+
+        let block_number = BlockNumber(3);
+        let expected_txs = {
+            let mut storage = cfg.pool.access_storage().await?;
+            storage
+                .chain()
+                .block_schema()
+                .get_block_transactions(block_number)
+                .await?
+        };
+        assert!(!expected_txs.is_empty());
+
+        let first_tx_hash = expected_txs
+            .first()
+            .unwrap()
+            .tx_hash
+            .as_str()
+            .replace("0x", "sync-tx:");
+        let from = TxHash::from_str(first_tx_hash.as_str()).unwrap();
+
+        let data = ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10));
+        let query = PaginationQuery {
+            from,
+            limit: expected_txs.len() as u32,
+            direction: PaginationDirection::Older,
+        };
+
+        let mut response = data.transaction_stream(block_number, query);
+        assert!(response.status().is_success());
+
+        let mut body = response.take_body();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.next().await {
+            bytes.extend_from_slice(&chunk.map_err(|err| anyhow::anyhow!(err.to_string()))?);
+        }
+
+        let mut seen = HashSet::new();
+        for line in String::from_utf8(bytes)?.lines() {
+            let tx: Transaction = serde_json::from_str(line)?;
+            let hash = tx.tx_hash.to_string();
+            assert!(seen.insert(hash.clone()), "duplicate transaction in stream: {}", hash);
+        }
+        assert_eq!(seen.len(), expected_txs.len());
+
+        Ok(())
+    }
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn v02_test_block_batch_dedupes_and_caps_positions() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+        let data = web::Data::new(ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10)));
+
+        let positions = vec!["2".to_string(), "2".to_string(), "last_committed".to_string()];
+        let result = data
+            .batch_block_info(&positions)
+            .await
+            .map_err(|err| anyhow::anyhow!(err.message))?;
+        assert_eq!(result.len(), positions.len());
+        assert_eq!(result[0], result[1]);
+
+        let oversized: Vec<String> = (0..=MAX_BLOCK_BATCH_SIZE as u32)
+            .map(|number| number.to_string())
+            .collect();
+        let response = block_batch(
+            data.clone(),
+            web::Json(BlockBatchBody {
+                positions: oversized,
+            }),
+        )
+        .await;
+        match response {
+            Either::B(resp) => assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST),
+            Either::A(_) => panic!("a batch over MAX_BLOCK_BATCH_SIZE should be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn v02_test_block_page_applies_status_filter() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+        let data = ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10));
+
+        let limit = 3;
+        let query = PaginationQuery {
+            from: BlockNumber(1),
+            limit,
+            direction: PaginationDirection::Newer,
+        };
+
+        let filter = BlockFilter {
+            status: Some(BlockStatusFilter::Committed),
+            ..Default::default()
+        };
+        let paginated = data
+            .block_page(query, filter)
+            .await
+            .map_err(|err| anyhow::anyhow!(err.message))?;
+        assert!(paginated.list.len() <= limit as usize);
+        assert!(paginated.count as usize >= paginated.list.len());
+
+        Ok(())
+    }
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn v02_test_block_data_shares_metrics_and_poller_across_instances() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+
+        let first = ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10));
+        let second = ApiBlockData::new(cfg.pool.clone(), BlockDetailsCache::new(10));
+
+        first.block_info(BlockNumber(1)).await?;
+        let gathered = String::from_utf8(second.metrics.gather())?;
+        assert!(gathered.contains("block_api_requests_total"));
+
+        assert_eq!(*first.block_updates.borrow(), *second.block_updates.borrow());
+
+        Ok(())
+    }
 }
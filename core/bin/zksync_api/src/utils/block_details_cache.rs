@@ -0,0 +1,53 @@
+//! Cache for verified block details.
+
+// Built-in uses
+use std::sync::Arc;
+
+// External uses
+use lru::LruCache;
+use tokio::sync::Mutex;
+
+// Workspace uses
+use zksync_storage::{chain::block::records::BlockDetails, ConnectionPool, QueryResult};
+use zksync_types::BlockNumber;
+
+/// Caches details of already verified blocks, since they never change once finalized.
+#[derive(Debug, Clone)]
+pub struct BlockDetailsCache(Arc<Mutex<LruCache<BlockNumber, BlockDetails>>>);
+
+impl BlockDetailsCache {
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(LruCache::new(capacity))))
+    }
+
+    /// Returns details of the given block together with a flag telling whether the
+    /// result was served from the cache (`true`) or fetched from storage (`false`).
+    pub async fn get(
+        &self,
+        pool: &ConnectionPool,
+        block_number: BlockNumber,
+    ) -> QueryResult<(Option<BlockDetails>, bool)> {
+        if let Some(details) = self.0.lock().await.get(&block_number).cloned() {
+            return Ok((Some(details), true));
+        }
+
+        let mut storage = pool.access_storage().await?;
+        let details = storage
+            .chain()
+            .block_schema()
+            .load_block_range(block_number, 1)
+            .await?
+            .into_iter()
+            .next()
+            // The range may end below `block_number` if that block does not exist yet.
+            .filter(|details| details.block_number == i64::from(*block_number));
+
+        if let Some(details) = &details {
+            if details.verified_at.is_some() {
+                self.0.lock().await.put(block_number, details.clone());
+            }
+        }
+
+        Ok((details, false))
+    }
+}
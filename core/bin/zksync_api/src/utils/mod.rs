@@ -0,0 +1,3 @@
+//! Miscellaneous utilities shared between API endpoints.
+
+pub mod block_details_cache;